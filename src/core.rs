@@ -9,6 +9,14 @@
 //!];
 //!```
 
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use std::io::Read;
+
+#[cfg(feature = "std")]
 use thiserror::Error;
 
 pub const VALUE_TERMINATOR: u8 = 0xFF;
@@ -16,74 +24,1076 @@ pub const ROW_TERMINATOR: u8 = 0xFD;
 pub const NULL_VALUE: u8 = 0xFE;
 
 pub fn encode_rsv<T: ToString>(rows: &[Vec<Option<T>>]) -> Vec<u8> {
+    encode_rsv_with(rows, EncodeOptions::strict())
+}
+
+/// Controls how nulls are written while encoding.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EncodeOptions {
+    /// When set, a null that is the last value of a row is written as a bare
+    /// [`NULL_VALUE`] byte, saving the trailing [`VALUE_TERMINATOR`]. Defaults to
+    /// `false`, i.e. the strict form every decoder understands.
+    pub compact_null: bool,
+}
+
+impl EncodeOptions {
+    /// The default, strict options terminating every value with a
+    /// [`VALUE_TERMINATOR`].
+    pub fn strict() -> Self {
+        EncodeOptions::default()
+    }
+
+    /// Options emitting the compact trailing-null form.
+    pub fn compact() -> Self {
+        EncodeOptions { compact_null: true }
+    }
+}
+
+/// Like [`encode_rsv`] but configurable through [`EncodeOptions`], e.g. to emit
+/// the compact trailing-null form accepted by [`decode_rsv_with`].
+pub fn encode_rsv_with<T: ToString>(rows: &[Vec<Option<T>>], options: EncodeOptions) -> Vec<u8> {
     rows.iter().fold(vec![], |mut result, row| {
-        let mut row_bytes = row
-            .iter()
-            .map(|v| match v {
-                Some(t_value) => t_value.to_string().into_bytes(),
-                None => vec![NULL_VALUE],
-            })
-            .fold(vec![], |mut row_result, mut value_in_bytes| {
-                row_result.append(&mut value_in_bytes);
-                row_result.push(VALUE_TERMINATOR);
-                row_result
-            });
-        result.append(&mut row_bytes);
+        let last_index = row.len().wrapping_sub(1);
+        for (index, value) in row.iter().enumerate() {
+            match value {
+                Some(t_value) => {
+                    result.append(&mut t_value.to_string().into_bytes());
+                    result.push(VALUE_TERMINATOR);
+                }
+                None => {
+                    result.push(NULL_VALUE);
+                    if !(options.compact_null && index == last_index) {
+                        result.push(VALUE_TERMINATOR);
+                    }
+                }
+            }
+        }
         result.push(ROW_TERMINATOR);
         result
     })
 }
 
+/// A single RSV value, able to carry a NULL, UTF-8 text, or an opaque byte
+/// string for the non-UTF-8 payloads the byte format allows between value
+/// terminators.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RsvValue {
+    Null,
+    Text(String),
+    Bytes(Vec<u8>),
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Error)]
+pub enum EncodeRSVErrors {
+    #[error("The byte value contains the reserved byte `{byte:#X}` at index `{index}`, which would corrupt the RSV structure!")]
+    ReservedByteInValue { index: usize, byte: u8 },
+}
+
+#[cfg(not(feature = "std"))]
+#[derive(Debug)]
+pub enum EncodeRSVErrors {
+    ReservedByteInValue { index: usize, byte: u8 },
+}
+
+#[cfg(not(feature = "std"))]
+impl ::core::fmt::Display for EncodeRSVErrors {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        match self {
+            EncodeRSVErrors::ReservedByteInValue { index, byte } => write!(
+                f,
+                "The byte value contains the reserved byte `{byte:#X}` at index `{index}`, which would corrupt the RSV structure!"
+            ),
+        }
+    }
+}
+
+/// Encodes rows of [`RsvValue`]s. RSV needs no escaping, so the bytes are
+/// written verbatim, but any `Bytes` value containing a structural byte
+/// (`0xFD`/`0xFE`/`0xFF`) is rejected since it would be indistinguishable from a
+/// terminator.
+pub fn encode_rsv_values(rows: &[Vec<RsvValue>]) -> Result<Vec<u8>, EncodeRSVErrors> {
+    let mut result: Vec<u8> = vec![];
+
+    for row in rows {
+        for value in row {
+            match value {
+                RsvValue::Null => result.push(NULL_VALUE),
+                RsvValue::Text(text) => result.extend_from_slice(text.as_bytes()),
+                RsvValue::Bytes(bytes) => {
+                    if let Some((index, byte)) = bytes.iter().enumerate().find(|(_, b)| {
+                        matches!(**b, VALUE_TERMINATOR | ROW_TERMINATOR | NULL_VALUE)
+                    }) {
+                        Err(EncodeRSVErrors::ReservedByteInValue { index, byte: *byte })?
+                    }
+                    result.extend_from_slice(bytes);
+                }
+            }
+            result.push(VALUE_TERMINATOR);
+        }
+        result.push(ROW_TERMINATOR);
+    }
+
+    Ok(result)
+}
+
+/// Decodes into [`RsvValue`]s, emitting `Bytes` for any value that is not valid
+/// UTF-8 instead of failing.
+pub fn decode_rsv_values(bytes: &[u8]) -> Result<Vec<Vec<RsvValue>>, DecodeRSVErrors> {
+    split_rows(bytes, |row_bytes, row_start_index, row_end_index| {
+        decode_row_values(row_bytes, row_start_index, row_end_index)
+    })
+}
+
+fn decode_row_values(
+    row_bytes: &[u8],
+    row_start_index: usize,
+    row_end_index: usize,
+) -> Result<Vec<RsvValue>, DecodeRSVErrors> {
+    let mut current_row: Vec<RsvValue> = vec![];
+
+    scan_row(
+        row_bytes,
+        row_start_index,
+        row_end_index,
+        DecodeOptions::strict(),
+        |value| {
+            current_row.push(match value {
+                RawValue::Empty => RsvValue::Text(String::new()),
+                RawValue::Null => RsvValue::Null,
+                RawValue::Bytes { slice, .. } => match ::core::str::from_utf8(slice) {
+                    Ok(str_value) => RsvValue::Text(str_value.to_string()),
+                    Err(_) => RsvValue::Bytes(slice.to_vec()),
+                },
+            });
+            Ok(())
+        },
+    )?;
+
+    Ok(current_row)
+}
+
+#[cfg(feature = "std")]
 #[derive(Debug, Error)]
 pub enum DecodeRSVErrors {
     #[error("The RSV file ends unexpectedly!")]
     IncompleteRSVDocument,
     #[error("The RSV row on byte number `{0}` ends unexpectedly!")]
     IncompleteRSVRow(usize),
-    #[error("Invalid UTF-8 byte sequence: {0:?}!")]
-    InvalidStringValue(#[from] std::string::FromUtf8Error),
+    #[error("Invalid UTF-8 byte sequence at byte `{offset}`: {source:?}!")]
+    InvalidStringValue {
+        offset: usize,
+        source: alloc::string::FromUtf8Error,
+    },
+    #[error("Failed to read from the underlying reader: {0}!")]
+    ReadError(#[from] std::io::Error),
+}
+
+#[cfg(not(feature = "std"))]
+#[derive(Debug)]
+pub enum DecodeRSVErrors {
+    IncompleteRSVDocument,
+    IncompleteRSVRow(usize),
+    InvalidStringValue {
+        offset: usize,
+        source: alloc::string::FromUtf8Error,
+    },
+}
+
+#[cfg(not(feature = "std"))]
+impl ::core::fmt::Display for DecodeRSVErrors {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        match self {
+            DecodeRSVErrors::IncompleteRSVDocument => {
+                write!(f, "The RSV file ends unexpectedly!")
+            }
+            DecodeRSVErrors::IncompleteRSVRow(byte) => {
+                write!(f, "The RSV row on byte number `{byte}` ends unexpectedly!")
+            }
+            DecodeRSVErrors::InvalidStringValue { offset, source } => {
+                write!(f, "Invalid UTF-8 byte sequence at byte `{offset}`: {source:?}!")
+            }
+        }
+    }
 }
 
 pub fn decode_rsv(bytes: &[u8]) -> Result<Vec<Vec<Option<String>>>, DecodeRSVErrors> {
+    decode_rows(bytes, false, DecodeOptions::strict())
+}
+
+/// Like [`decode_rsv`] but never fails on invalid UTF-8: any value that is not
+/// valid UTF-8 keeps its valid prefixes and has every invalid sequence replaced
+/// by the U+FFFD replacement character.
+pub fn decode_rsv_lossy(bytes: &[u8]) -> Result<Vec<Vec<Option<String>>>, DecodeRSVErrors> {
+    decode_rows(bytes, true, DecodeOptions::strict())
+}
+
+/// Controls how nulls are recognized while decoding.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DecodeOptions {
+    /// When set, a bare [`NULL_VALUE`] byte at the end of a row (not followed by
+    /// a [`VALUE_TERMINATOR`]) is accepted as a null value instead of raising
+    /// `IncompleteRSVRow`. Defaults to `false`, i.e. strict decoding.
+    pub compact_null: bool,
+}
+
+impl DecodeOptions {
+    /// The default, strict options requiring every value to end with a
+    /// [`VALUE_TERMINATOR`].
+    pub fn strict() -> Self {
+        DecodeOptions::default()
+    }
+
+    /// Options that also accept the compact trailing-null form.
+    pub fn compact() -> Self {
+        DecodeOptions { compact_null: true }
+    }
+}
+
+/// Like [`decode_rsv`] but configurable through [`DecodeOptions`], e.g. to accept
+/// the compact trailing-null form written by [`encode_rsv_with`].
+pub fn decode_rsv_with(
+    bytes: &[u8],
+    options: DecodeOptions,
+) -> Result<Vec<Vec<Option<String>>>, DecodeRSVErrors> {
+    decode_rows(bytes, false, options)
+}
+
+fn decode_rows(
+    bytes: &[u8],
+    lossy: bool,
+    options: DecodeOptions,
+) -> Result<Vec<Vec<Option<String>>>, DecodeRSVErrors> {
+    split_rows(bytes, |row_bytes, row_start_index, row_end_index| {
+        decode_row(row_bytes, row_start_index, row_end_index, lossy, options)
+    })
+}
+
+/// Splits `bytes` on `ROW_TERMINATOR` and decodes each row with `decode_row`,
+/// passing it the row slice together with its absolute start index and the
+/// absolute index just past its terminator. Shared by the string and [`RsvValue`] decoding paths so both the
+/// document-completeness check and the row-splitting logic live in exactly one
+/// place.
+fn split_rows<V>(
+    bytes: &[u8],
+    mut decode_row: impl FnMut(&[u8], usize, usize) -> Result<V, DecodeRSVErrors>,
+) -> Result<Vec<V>, DecodeRSVErrors> {
     if bytes.last() != Some(&ROW_TERMINATOR) {
         Err(DecodeRSVErrors::IncompleteRSVDocument)?
     }
 
-    let mut result: Vec<Vec<Option<String>>> = vec![];
-    let mut current_row: Vec<Option<String>> = vec![];
-    let mut value_start_index = 0;
+    let mut result: Vec<V> = vec![];
+    let mut row_start_index = 0;
 
     for i in 0..bytes.len() {
-        match bytes[i] {
-            VALUE_TERMINATOR => {
-                let length = i - value_start_index;
-
-                match (length, bytes[value_start_index]) {
-                    (0, _) => current_row.push(Some(String::new())),
-                    (1, NULL_VALUE) => current_row.push(None),
-                    (_, _) => {
-                        let value_bytes = bytes[value_start_index..i].to_vec();
-                        match String::from_utf8(value_bytes) {
-                            Ok(str_value) => current_row.push(Some(str_value)),
-                            Err(err) => Err(DecodeRSVErrors::InvalidStringValue(err))?,
-                        }
-                    }
+        if bytes[i] == ROW_TERMINATOR {
+            result.push(decode_row(&bytes[row_start_index..i], row_start_index, i + 1)?);
+            row_start_index = i + 1;
+        }
+    }
+
+    Ok(result)
+}
+
+/// A single value as recognized by [`scan_row`], before it is turned into a
+/// `String` or an [`RsvValue`].
+enum RawValue<'a> {
+    Empty,
+    Null,
+    /// A non-empty, non-null value slice together with its absolute byte offset
+    /// in the source document.
+    Bytes { slice: &'a [u8], offset: usize },
+}
+
+/// Scans the bytes of a single row (the `ROW_TERMINATOR` excluded), invoking
+/// `emit` once per value with its shared empty/null classification. Emitting
+/// directly lets callers build their target vec without an intermediate one.
+///
+/// `row_start_index` is the absolute index of the first byte of the row, used to
+/// report each value's offset. `row_end_index` is the absolute index just past
+/// the row terminator (the terminator's 1-based byte number), used only to
+/// report `IncompleteRSVRow`. When
+/// `options.compact_null` is set, a bare trailing [`NULL_VALUE`] is accepted as a
+/// null value.
+fn scan_row(
+    row_bytes: &[u8],
+    row_start_index: usize,
+    row_end_index: usize,
+    options: DecodeOptions,
+    mut emit: impl FnMut(RawValue<'_>) -> Result<(), DecodeRSVErrors>,
+) -> Result<(), DecodeRSVErrors> {
+    let mut value_start_index = 0;
+
+    for i in 0..row_bytes.len() {
+        if row_bytes[i] == VALUE_TERMINATOR {
+            let length = i - value_start_index;
+
+            let value = match (length, row_bytes[value_start_index]) {
+                (0, _) => RawValue::Empty,
+                (1, NULL_VALUE) => RawValue::Null,
+                (_, _) => RawValue::Bytes {
+                    slice: &row_bytes[value_start_index..i],
+                    offset: row_start_index + value_start_index,
+                },
+            };
+            emit(value)?;
+
+            value_start_index = i + 1;
+        }
+    }
+
+    if value_start_index != row_bytes.len() {
+        let trailing = &row_bytes[value_start_index..];
+        if options.compact_null && trailing.len() == 1 && trailing[0] == NULL_VALUE {
+            emit(RawValue::Null)?;
+        } else {
+            Err(DecodeRSVErrors::IncompleteRSVRow(row_end_index))?
+        }
+    }
+
+    Ok(())
+}
+
+/// Decodes the bytes of a single row into its string values, reusing
+/// [`scan_row`]. `lossy` selects lossy UTF-8 handling; `options` is threaded to
+/// [`scan_row`] for compact-null recognition.
+fn decode_row(
+    row_bytes: &[u8],
+    row_start_index: usize,
+    row_end_index: usize,
+    lossy: bool,
+    options: DecodeOptions,
+) -> Result<Vec<Option<String>>, DecodeRSVErrors> {
+    let mut current_row: Vec<Option<String>> = vec![];
+
+    scan_row(row_bytes, row_start_index, row_end_index, options, |value| {
+        current_row.push(match value {
+            RawValue::Empty => Some(String::new()),
+            RawValue::Null => None,
+            RawValue::Bytes { slice, offset } => Some(decode_value(slice, offset, lossy)?),
+        });
+        Ok(())
+    })?;
+
+    Ok(current_row)
+}
+
+/// Decodes a single value slice into a `String`. In strict mode an invalid
+/// sequence yields `InvalidStringValue` carrying its absolute byte offset
+/// (`value_start` plus `valid_up_to`); in lossy mode every invalid sequence is
+/// replaced by U+FFFD.
+fn decode_value(
+    value_bytes: &[u8],
+    value_start: usize,
+    lossy: bool,
+) -> Result<String, DecodeRSVErrors> {
+    match ::core::str::from_utf8(value_bytes) {
+        Ok(str_value) => Ok(str_value.to_string()),
+        Err(_) if lossy => Ok(decode_value_lossy(value_bytes)),
+        Err(utf8_error) => {
+            let offset = value_start + utf8_error.valid_up_to();
+            let source = String::from_utf8(value_bytes.to_vec()).unwrap_err();
+            Err(DecodeRSVErrors::InvalidStringValue { offset, source })
+        }
+    }
+}
+
+fn decode_value_lossy(mut value_bytes: &[u8]) -> String {
+    let mut value = String::new();
+
+    loop {
+        match ::core::str::from_utf8(value_bytes) {
+            Ok(str_value) => {
+                value.push_str(str_value);
+                break;
+            }
+            Err(utf8_error) => {
+                let valid_up_to = utf8_error.valid_up_to();
+                // SAFETY: `valid_up_to` bytes are a valid UTF-8 prefix by definition.
+                value.push_str(unsafe {
+                    ::core::str::from_utf8_unchecked(&value_bytes[..valid_up_to])
+                });
+                value.push('\u{FFFD}');
+
+                match utf8_error.error_len() {
+                    Some(error_len) => value_bytes = &value_bytes[valid_up_to + error_len..],
+                    None => break,
                 }
+            }
+        }
+    }
 
-                value_start_index = i + 1;
+    value
+}
+
+#[cfg(feature = "std")]
+const DEFAULT_CHUNK_SIZE: usize = 8 * 1024;
+
+/// Streaming decoder that yields one row at a time from any [`Read`], keeping
+/// memory bounded regardless of the document size.
+#[cfg(feature = "std")]
+pub struct RsvReader<R: Read> {
+    reader: R,
+    buffer: Vec<u8>,
+    chunk_size: usize,
+    done: bool,
+    /// Absolute byte offset in the document of the first byte currently held in
+    /// `buffer`, so streamed rows report the same offsets as [`decode_rsv`].
+    position: usize,
+}
+
+#[cfg(feature = "std")]
+impl<R: Read> RsvReader<R> {
+    pub fn new(reader: R) -> Self {
+        Self::with_chunk_size(reader, DEFAULT_CHUNK_SIZE)
+    }
+
+    pub fn with_chunk_size(reader: R, chunk_size: usize) -> Self {
+        RsvReader {
+            reader,
+            buffer: vec![],
+            chunk_size,
+            done: false,
+            position: 0,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R: Read> Iterator for RsvReader<R> {
+    type Item = Result<Vec<Option<String>>, DecodeRSVErrors>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        loop {
+            if let Some(i) = self.buffer.iter().position(|&b| b == ROW_TERMINATOR) {
+                let row_bytes: Vec<u8> = self.buffer.drain(..=i).collect();
+                let row_start_index = self.position;
+                self.position += i + 1;
+                return Some(decode_row(
+                    &row_bytes[..i],
+                    row_start_index,
+                    row_start_index + i + 1,
+                    false,
+                    DecodeOptions::strict(),
+                ));
             }
-            ROW_TERMINATOR => {
-                if i > 0 && value_start_index != i {
-                    Err(DecodeRSVErrors::IncompleteRSVRow(i + 1))?
+
+            let mut scratch = vec![0u8; self.chunk_size];
+            match self.reader.read(&mut scratch) {
+                Ok(0) => {
+                    self.done = true;
+                    return if self.buffer.is_empty() {
+                        None
+                    } else {
+                        Some(Err(DecodeRSVErrors::IncompleteRSVDocument))
+                    };
+                }
+                Ok(read) => self.buffer.extend_from_slice(&scratch[..read]),
+                Err(err) => {
+                    self.done = true;
+                    return Some(Err(DecodeRSVErrors::ReadError(err)));
                 }
+            }
+        }
+    }
+}
 
-                result.push(current_row);
-                current_row = Vec::new();
-                value_start_index = i + 1;
+/// Optional `serde` support mapping each RSV row to one record `T`, following
+/// rust-csv's record model: every value becomes a field, `None`/NULL maps to an
+/// `Option` field, and string values are parsed into the target scalar type.
+#[cfg(feature = "serde")]
+pub mod serde_support {
+    use ::core::fmt::{self, Display};
+
+    use alloc::format;
+    use alloc::string::{String, ToString};
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    use serde::de::{DeserializeOwned, SeqAccess, Visitor};
+    use serde::ser::{Impossible, SerializeMap, SerializeSeq, SerializeStruct, SerializeTuple};
+    use serde::ser::{SerializeTupleStruct, SerializeTupleVariant};
+    use serde::{Deserializer, Serialize, Serializer};
+
+    use super::{decode_rsv, encode_rsv, DecodeRSVErrors};
+
+    #[derive(Debug)]
+    pub enum RsvSerdeError {
+        Decode(DecodeRSVErrors),
+        Message(String),
+        UnsupportedType(&'static str),
+    }
+
+    impl From<DecodeRSVErrors> for RsvSerdeError {
+        fn from(error: DecodeRSVErrors) -> Self {
+            RsvSerdeError::Decode(error)
+        }
+    }
+
+    impl Display for RsvSerdeError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                RsvSerdeError::Decode(error) => write!(f, "{error:?}"),
+                RsvSerdeError::Message(message) => write!(f, "{message}"),
+                RsvSerdeError::UnsupportedType(kind) => {
+                    write!(f, "the `{kind}` type is not supported by the RSV data model")
+                }
             }
-            _ => {}
         }
     }
 
-    Ok(result)
+    // `serde::ser::Error`/`de::Error` require `core::error::Error`, so implement
+    // it unconditionally rather than gating on `std` (it is a re-export of this
+    // trait and would leave the bound unmet under `no_std`).
+    impl ::core::error::Error for RsvSerdeError {}
+
+    impl serde::ser::Error for RsvSerdeError {
+        fn custom<T: Display>(msg: T) -> Self {
+            RsvSerdeError::Message(msg.to_string())
+        }
+    }
+
+    impl serde::de::Error for RsvSerdeError {
+        fn custom<T: Display>(msg: T) -> Self {
+            RsvSerdeError::Message(msg.to_string())
+        }
+    }
+
+    /// Decodes `bytes` and deserializes each row into a `T`.
+    pub fn from_rsv<T: DeserializeOwned>(bytes: &[u8]) -> Result<Vec<T>, RsvSerdeError> {
+        decode_rsv(bytes)?
+            .into_iter()
+            .map(|row| T::deserialize(RowDeserializer::new(row)))
+            .collect()
+    }
+
+    /// Serializes each record into an RSV row and encodes the document.
+    pub fn to_rsv<T: Serialize>(records: &[T]) -> Result<Vec<u8>, RsvSerdeError> {
+        let rows = records
+            .iter()
+            .map(|record| record.serialize(RecordSerializer))
+            .collect::<Result<Vec<Vec<Option<String>>>, RsvSerdeError>>()?;
+
+        Ok(encode_rsv(&rows))
+    }
+
+    // --- Serialization -------------------------------------------------------
+
+    struct RecordSerializer;
+
+    /// Accumulates the fields of a single record as they are serialized.
+    struct RowSerializer {
+        values: Vec<Option<String>>,
+    }
+
+    impl RowSerializer {
+        fn new() -> Self {
+            RowSerializer { values: vec![] }
+        }
+
+        fn push<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), RsvSerdeError> {
+            self.values.push(value.serialize(FieldSerializer)?);
+            Ok(())
+        }
+    }
+
+    impl Serializer for RecordSerializer {
+        type Ok = Vec<Option<String>>;
+        type Error = RsvSerdeError;
+        type SerializeSeq = RowSerializer;
+        type SerializeTuple = RowSerializer;
+        type SerializeTupleStruct = RowSerializer;
+        type SerializeTupleVariant = RowSerializer;
+        type SerializeMap = RowSerializer;
+        type SerializeStruct = RowSerializer;
+        type SerializeStructVariant = Impossible<Self::Ok, Self::Error>;
+
+        fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+            Ok(RowSerializer::new())
+        }
+        fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+            Ok(RowSerializer::new())
+        }
+        fn serialize_tuple_struct(
+            self,
+            _name: &'static str,
+            _len: usize,
+        ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+            Ok(RowSerializer::new())
+        }
+        fn serialize_tuple_variant(
+            self,
+            _name: &'static str,
+            _index: u32,
+            _variant: &'static str,
+            _len: usize,
+        ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+            Ok(RowSerializer::new())
+        }
+        fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+            Ok(RowSerializer::new())
+        }
+        fn serialize_struct(
+            self,
+            _name: &'static str,
+            _len: usize,
+        ) -> Result<Self::SerializeStruct, Self::Error> {
+            Ok(RowSerializer::new())
+        }
+        fn serialize_newtype_struct<T: ?Sized + Serialize>(
+            self,
+            _name: &'static str,
+            value: &T,
+        ) -> Result<Self::Ok, Self::Error> {
+            value.serialize(self)
+        }
+        fn serialize_newtype_variant<T: ?Sized + Serialize>(
+            self,
+            _name: &'static str,
+            _index: u32,
+            _variant: &'static str,
+            _value: &T,
+        ) -> Result<Self::Ok, Self::Error> {
+            // Forwarding the inner value would drop the variant tag, silently
+            // turning distinct variants into identical rows. Reject it, matching
+            // `serialize_struct_variant`.
+            Err(RsvSerdeError::UnsupportedType("newtype variant"))
+        }
+        fn serialize_struct_variant(
+            self,
+            _name: &'static str,
+            _index: u32,
+            _variant: &'static str,
+            _len: usize,
+        ) -> Result<Self::SerializeStructVariant, Self::Error> {
+            Err(RsvSerdeError::UnsupportedType("struct variant"))
+        }
+
+        fn serialize_bool(self, _v: bool) -> Result<Self::Ok, Self::Error> {
+            Err(RsvSerdeError::UnsupportedType("bare scalar record"))
+        }
+        fn serialize_i64(self, _v: i64) -> Result<Self::Ok, Self::Error> {
+            Err(RsvSerdeError::UnsupportedType("bare scalar record"))
+        }
+        fn serialize_u64(self, _v: u64) -> Result<Self::Ok, Self::Error> {
+            Err(RsvSerdeError::UnsupportedType("bare scalar record"))
+        }
+        fn serialize_f64(self, _v: f64) -> Result<Self::Ok, Self::Error> {
+            Err(RsvSerdeError::UnsupportedType("bare scalar record"))
+        }
+        fn serialize_str(self, _v: &str) -> Result<Self::Ok, Self::Error> {
+            Err(RsvSerdeError::UnsupportedType("bare scalar record"))
+        }
+        fn serialize_i8(self, v: i8) -> Result<Self::Ok, Self::Error> {
+            self.serialize_i64(v as i64)
+        }
+        fn serialize_i16(self, v: i16) -> Result<Self::Ok, Self::Error> {
+            self.serialize_i64(v as i64)
+        }
+        fn serialize_i32(self, v: i32) -> Result<Self::Ok, Self::Error> {
+            self.serialize_i64(v as i64)
+        }
+        fn serialize_u8(self, v: u8) -> Result<Self::Ok, Self::Error> {
+            self.serialize_u64(v as u64)
+        }
+        fn serialize_u16(self, v: u16) -> Result<Self::Ok, Self::Error> {
+            self.serialize_u64(v as u64)
+        }
+        fn serialize_u32(self, v: u32) -> Result<Self::Ok, Self::Error> {
+            self.serialize_u64(v as u64)
+        }
+        fn serialize_f32(self, v: f32) -> Result<Self::Ok, Self::Error> {
+            self.serialize_f64(v as f64)
+        }
+        fn serialize_char(self, _v: char) -> Result<Self::Ok, Self::Error> {
+            Err(RsvSerdeError::UnsupportedType("bare scalar record"))
+        }
+        fn serialize_bytes(self, _v: &[u8]) -> Result<Self::Ok, Self::Error> {
+            Err(RsvSerdeError::UnsupportedType("bare scalar record"))
+        }
+        fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+            Err(RsvSerdeError::UnsupportedType("bare scalar record"))
+        }
+        fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<Self::Ok, Self::Error> {
+            value.serialize(self)
+        }
+        fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+            Err(RsvSerdeError::UnsupportedType("bare scalar record"))
+        }
+        fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok, Self::Error> {
+            Err(RsvSerdeError::UnsupportedType("bare scalar record"))
+        }
+        fn serialize_unit_variant(
+            self,
+            _name: &'static str,
+            _index: u32,
+            variant: &'static str,
+        ) -> Result<Self::Ok, Self::Error> {
+            Ok(vec![Some(variant.to_string())])
+        }
+    }
+
+    impl SerializeSeq for RowSerializer {
+        type Ok = Vec<Option<String>>;
+        type Error = RsvSerdeError;
+        fn serialize_element<T: ?Sized + Serialize>(
+            &mut self,
+            value: &T,
+        ) -> Result<(), Self::Error> {
+            self.push(value)
+        }
+        fn end(self) -> Result<Self::Ok, Self::Error> {
+            Ok(self.values)
+        }
+    }
+
+    impl SerializeTuple for RowSerializer {
+        type Ok = Vec<Option<String>>;
+        type Error = RsvSerdeError;
+        fn serialize_element<T: ?Sized + Serialize>(
+            &mut self,
+            value: &T,
+        ) -> Result<(), Self::Error> {
+            self.push(value)
+        }
+        fn end(self) -> Result<Self::Ok, Self::Error> {
+            Ok(self.values)
+        }
+    }
+
+    impl SerializeTupleStruct for RowSerializer {
+        type Ok = Vec<Option<String>>;
+        type Error = RsvSerdeError;
+        fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Self::Error> {
+            self.push(value)
+        }
+        fn end(self) -> Result<Self::Ok, Self::Error> {
+            Ok(self.values)
+        }
+    }
+
+    impl SerializeTupleVariant for RowSerializer {
+        type Ok = Vec<Option<String>>;
+        type Error = RsvSerdeError;
+        fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Self::Error> {
+            self.push(value)
+        }
+        fn end(self) -> Result<Self::Ok, Self::Error> {
+            Ok(self.values)
+        }
+    }
+
+    impl SerializeMap for RowSerializer {
+        type Ok = Vec<Option<String>>;
+        type Error = RsvSerdeError;
+        fn serialize_key<T: ?Sized + Serialize>(&mut self, _key: &T) -> Result<(), Self::Error> {
+            Ok(())
+        }
+        fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Self::Error> {
+            self.push(value)
+        }
+        fn end(self) -> Result<Self::Ok, Self::Error> {
+            Ok(self.values)
+        }
+    }
+
+    impl SerializeStruct for RowSerializer {
+        type Ok = Vec<Option<String>>;
+        type Error = RsvSerdeError;
+        fn serialize_field<T: ?Sized + Serialize>(
+            &mut self,
+            _key: &'static str,
+            value: &T,
+        ) -> Result<(), Self::Error> {
+            self.push(value)
+        }
+        fn end(self) -> Result<Self::Ok, Self::Error> {
+            Ok(self.values)
+        }
+    }
+
+    /// Serializes a single field into its `Option<String>` cell.
+    struct FieldSerializer;
+
+    impl Serializer for FieldSerializer {
+        type Ok = Option<String>;
+        type Error = RsvSerdeError;
+        type SerializeSeq = Impossible<Self::Ok, Self::Error>;
+        type SerializeTuple = Impossible<Self::Ok, Self::Error>;
+        type SerializeTupleStruct = Impossible<Self::Ok, Self::Error>;
+        type SerializeTupleVariant = Impossible<Self::Ok, Self::Error>;
+        type SerializeMap = Impossible<Self::Ok, Self::Error>;
+        type SerializeStruct = Impossible<Self::Ok, Self::Error>;
+        type SerializeStructVariant = Impossible<Self::Ok, Self::Error>;
+
+        fn serialize_bool(self, v: bool) -> Result<Self::Ok, Self::Error> {
+            Ok(Some(v.to_string()))
+        }
+        fn serialize_i64(self, v: i64) -> Result<Self::Ok, Self::Error> {
+            Ok(Some(v.to_string()))
+        }
+        fn serialize_u64(self, v: u64) -> Result<Self::Ok, Self::Error> {
+            Ok(Some(v.to_string()))
+        }
+        fn serialize_f64(self, v: f64) -> Result<Self::Ok, Self::Error> {
+            Ok(Some(v.to_string()))
+        }
+        fn serialize_i8(self, v: i8) -> Result<Self::Ok, Self::Error> {
+            self.serialize_i64(v as i64)
+        }
+        fn serialize_i16(self, v: i16) -> Result<Self::Ok, Self::Error> {
+            self.serialize_i64(v as i64)
+        }
+        fn serialize_i32(self, v: i32) -> Result<Self::Ok, Self::Error> {
+            self.serialize_i64(v as i64)
+        }
+        fn serialize_u8(self, v: u8) -> Result<Self::Ok, Self::Error> {
+            self.serialize_u64(v as u64)
+        }
+        fn serialize_u16(self, v: u16) -> Result<Self::Ok, Self::Error> {
+            self.serialize_u64(v as u64)
+        }
+        fn serialize_u32(self, v: u32) -> Result<Self::Ok, Self::Error> {
+            self.serialize_u64(v as u64)
+        }
+        fn serialize_f32(self, v: f32) -> Result<Self::Ok, Self::Error> {
+            self.serialize_f64(v as f64)
+        }
+        fn serialize_char(self, v: char) -> Result<Self::Ok, Self::Error> {
+            Ok(Some(v.to_string()))
+        }
+        fn serialize_str(self, v: &str) -> Result<Self::Ok, Self::Error> {
+            Ok(Some(v.to_string()))
+        }
+        fn serialize_bytes(self, _v: &[u8]) -> Result<Self::Ok, Self::Error> {
+            Err(RsvSerdeError::UnsupportedType("byte string field"))
+        }
+        fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+            Ok(None)
+        }
+        fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<Self::Ok, Self::Error> {
+            value.serialize(self)
+        }
+        fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+            Ok(None)
+        }
+        fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok, Self::Error> {
+            Ok(None)
+        }
+        fn serialize_unit_variant(
+            self,
+            _name: &'static str,
+            _index: u32,
+            variant: &'static str,
+        ) -> Result<Self::Ok, Self::Error> {
+            Ok(Some(variant.to_string()))
+        }
+        fn serialize_newtype_struct<T: ?Sized + Serialize>(
+            self,
+            _name: &'static str,
+            value: &T,
+        ) -> Result<Self::Ok, Self::Error> {
+            value.serialize(self)
+        }
+        fn serialize_newtype_variant<T: ?Sized + Serialize>(
+            self,
+            _name: &'static str,
+            _index: u32,
+            _variant: &'static str,
+            _value: &T,
+        ) -> Result<Self::Ok, Self::Error> {
+            // A variant tag cannot be represented in a single value cell; drop
+            // to an error rather than silently losing it.
+            Err(RsvSerdeError::UnsupportedType("newtype variant field"))
+        }
+        fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+            Err(RsvSerdeError::UnsupportedType("nested sequence field"))
+        }
+        fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+            Err(RsvSerdeError::UnsupportedType("nested tuple field"))
+        }
+        fn serialize_tuple_struct(
+            self,
+            _name: &'static str,
+            _len: usize,
+        ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+            Err(RsvSerdeError::UnsupportedType("nested tuple struct field"))
+        }
+        fn serialize_tuple_variant(
+            self,
+            _name: &'static str,
+            _index: u32,
+            _variant: &'static str,
+            _len: usize,
+        ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+            Err(RsvSerdeError::UnsupportedType("nested tuple variant field"))
+        }
+        fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+            Err(RsvSerdeError::UnsupportedType("nested map field"))
+        }
+        fn serialize_struct(
+            self,
+            _name: &'static str,
+            _len: usize,
+        ) -> Result<Self::SerializeStruct, Self::Error> {
+            Err(RsvSerdeError::UnsupportedType("nested struct field"))
+        }
+        fn serialize_struct_variant(
+            self,
+            _name: &'static str,
+            _index: u32,
+            _variant: &'static str,
+            _len: usize,
+        ) -> Result<Self::SerializeStructVariant, Self::Error> {
+            Err(RsvSerdeError::UnsupportedType("nested struct variant field"))
+        }
+    }
+
+    // --- Deserialization -----------------------------------------------------
+
+    /// Walks a decoded row field-by-field for a single record `T`.
+    struct RowDeserializer {
+        values: alloc::vec::IntoIter<Option<String>>,
+    }
+
+    impl RowDeserializer {
+        fn new(values: Vec<Option<String>>) -> Self {
+            RowDeserializer {
+                values: values.into_iter(),
+            }
+        }
+    }
+
+    impl<'de> Deserializer<'de> for RowDeserializer {
+        type Error = RsvSerdeError;
+
+        fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+            visitor.visit_seq(RowSeqAccess {
+                values: self.values,
+            })
+        }
+
+        serde::forward_to_deserialize_any! {
+            bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+            bytes byte_buf option unit unit_struct newtype_struct seq tuple
+            tuple_struct map struct enum identifier ignored_any
+        }
+    }
+
+    struct RowSeqAccess {
+        values: alloc::vec::IntoIter<Option<String>>,
+    }
+
+    impl<'de> SeqAccess<'de> for RowSeqAccess {
+        type Error = RsvSerdeError;
+
+        fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Self::Error>
+        where
+            T: serde::de::DeserializeSeed<'de>,
+        {
+            match self.values.next() {
+                Some(value) => seed.deserialize(FieldDeserializer { value }).map(Some),
+                None => Ok(None),
+            }
+        }
+
+        fn size_hint(&self) -> Option<usize> {
+            Some(self.values.len())
+        }
+    }
+
+    /// Deserializes a single field from its `Option<String>` cell, parsing the
+    /// string into the target scalar type on demand.
+    struct FieldDeserializer {
+        value: Option<String>,
+    }
+
+    impl FieldDeserializer {
+        fn as_str(&self) -> Result<&str, RsvSerdeError> {
+            self.value
+                .as_deref()
+                .ok_or_else(|| RsvSerdeError::Message("unexpected NULL for non-optional field".into()))
+        }
+    }
+
+    macro_rules! deserialize_parsed {
+        ($method:ident, $visit:ident, $ty:ty) => {
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+                let parsed = self
+                    .as_str()?
+                    .parse::<$ty>()
+                    .map_err(|err| RsvSerdeError::Message(err.to_string()))?;
+                visitor.$visit(parsed)
+            }
+        };
+    }
+
+    impl<'de> Deserializer<'de> for FieldDeserializer {
+        type Error = RsvSerdeError;
+
+        fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+            visitor.visit_string(self.as_str()?.to_string())
+        }
+
+        fn deserialize_option<V: Visitor<'de>>(
+            self,
+            visitor: V,
+        ) -> Result<V::Value, Self::Error> {
+            match self.value {
+                Some(_) => visitor.visit_some(self),
+                None => visitor.visit_none(),
+            }
+        }
+
+        fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+            visitor.visit_str(self.as_str()?)
+        }
+
+        fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+            self.deserialize_str(visitor)
+        }
+
+        fn deserialize_char<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+            let text = self.as_str()?;
+            let mut chars = text.chars();
+            match (chars.next(), chars.next()) {
+                (Some(character), None) => visitor.visit_char(character),
+                _ => Err(RsvSerdeError::Message(format!(
+                    "expected a single character, found `{text}`"
+                ))),
+            }
+        }
+
+        fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+            visitor.visit_unit()
+        }
+
+        fn deserialize_ignored_any<V: Visitor<'de>>(
+            self,
+            visitor: V,
+        ) -> Result<V::Value, Self::Error> {
+            visitor.visit_unit()
+        }
+
+        deserialize_parsed!(deserialize_bool, visit_bool, bool);
+        deserialize_parsed!(deserialize_i8, visit_i8, i8);
+        deserialize_parsed!(deserialize_i16, visit_i16, i16);
+        deserialize_parsed!(deserialize_i32, visit_i32, i32);
+        deserialize_parsed!(deserialize_i64, visit_i64, i64);
+        deserialize_parsed!(deserialize_u8, visit_u8, u8);
+        deserialize_parsed!(deserialize_u16, visit_u16, u16);
+        deserialize_parsed!(deserialize_u32, visit_u32, u32);
+        deserialize_parsed!(deserialize_u64, visit_u64, u64);
+        deserialize_parsed!(deserialize_f32, visit_f32, f32);
+        deserialize_parsed!(deserialize_f64, visit_f64, f64);
+
+        serde::forward_to_deserialize_any! {
+            i128 u128 bytes byte_buf unit_struct newtype_struct seq tuple
+            tuple_struct map struct enum identifier
+        }
+    }
 }
@@ -0,0 +1,6 @@
+//!`rsv-data` encodes and decodes the Rows of String Values (RSV) binary format.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub mod core;
@@ -0,0 +1,41 @@
+use rsv_data::core::{
+    decode_rsv, decode_rsv_with, encode_rsv, encode_rsv_with, DecodeOptions, DecodeRSVErrors,
+    EncodeOptions,
+};
+
+fn rows() -> Vec<Vec<Option<String>>> {
+    vec![vec![Some("x".to_string()), None]]
+}
+
+#[test]
+fn compact_encoding_saves_one_byte_per_trailing_null() {
+    let strict = encode_rsv(&rows());
+    let compact = encode_rsv_with(&rows(), EncodeOptions::compact());
+
+    assert_eq!(compact.len(), strict.len() - 1);
+    // The row ends with a bare NULL_VALUE directly followed by the row terminator.
+    assert_eq!(
+        &compact[compact.len() - 2..],
+        &[rsv_data::core::NULL_VALUE, rsv_data::core::ROW_TERMINATOR]
+    );
+}
+
+#[test]
+fn compact_round_trips_with_compact_decoder() {
+    let compact = encode_rsv_with(&rows(), EncodeOptions::compact());
+    assert_eq!(decode_rsv_with(&compact, DecodeOptions::compact()).unwrap(), rows());
+}
+
+#[test]
+fn strict_decoder_rejects_compact_form() {
+    let compact = encode_rsv_with(&rows(), EncodeOptions::compact());
+    assert!(matches!(
+        decode_rsv(&compact),
+        Err(DecodeRSVErrors::IncompleteRSVRow(_))
+    ));
+}
+
+#[test]
+fn strict_encoding_is_unchanged_by_default() {
+    assert_eq!(encode_rsv(&rows()), encode_rsv_with(&rows(), EncodeOptions::strict()));
+}
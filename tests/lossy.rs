@@ -0,0 +1,21 @@
+use rsv_data::core::{decode_rsv, decode_rsv_lossy, DecodeRSVErrors};
+
+// Row with a valid value "hi" followed by a value "A" plus a lone 0x80 byte.
+const INVALID: [u8; 7] = [b'h', b'i', 0xFF, b'A', 0x80, 0xFF, 0xFD];
+
+#[test]
+fn strict_decode_reports_absolute_offset() {
+    match decode_rsv(&INVALID) {
+        Err(DecodeRSVErrors::InvalidStringValue { offset, .. }) => assert_eq!(offset, 4),
+        other => panic!("expected InvalidStringValue at offset 4, got {other:?}"),
+    }
+}
+
+#[test]
+fn lossy_decode_replaces_invalid_sequences() {
+    let rows = decode_rsv_lossy(&INVALID).expect("lossy decode never fails on bad UTF-8");
+    assert_eq!(
+        rows,
+        vec![vec![Some("hi".to_string()), Some("A\u{FFFD}".to_string())]]
+    );
+}
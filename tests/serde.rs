@@ -0,0 +1,30 @@
+use rsv_data::core::serde_support::{from_rsv, to_rsv};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Record {
+    name: String,
+    age: u32,
+    nickname: Option<String>,
+}
+
+#[test]
+fn round_trips_typed_records() {
+    let records = vec![
+        Record {
+            name: "Ada".to_string(),
+            age: 36,
+            nickname: Some("countess".to_string()),
+        },
+        Record {
+            name: "Grace".to_string(),
+            age: 85,
+            nickname: None,
+        },
+    ];
+
+    let encoded = to_rsv(&records).expect("serialization succeeds");
+    let decoded: Vec<Record> = from_rsv(&encoded).expect("deserialization succeeds");
+
+    assert_eq!(decoded, records);
+}
@@ -0,0 +1,43 @@
+use std::io::Cursor;
+
+use rsv_data::core::{decode_rsv, DecodeRSVErrors, RsvReader};
+
+#[test]
+fn streams_rows_across_chunk_boundaries() {
+    let rows = vec![
+        vec![Some("hello".to_string()), None],
+        vec![Some("a".to_string()), Some("b".to_string())],
+        vec![Some("last".to_string())],
+    ];
+    let encoded = rsv_data::core::encode_rsv(&rows);
+
+    // A tiny chunk size forces rows to span several reads.
+    let reader = RsvReader::with_chunk_size(Cursor::new(encoded.clone()), 3);
+    let streamed: Vec<_> = reader
+        .collect::<Result<Vec<_>, _>>()
+        .expect("streaming decode succeeds");
+
+    assert_eq!(streamed, decode_rsv(&encoded).unwrap());
+}
+
+#[test]
+fn streaming_offset_matches_whole_document_decode() {
+    // Row 0 is valid; row 1 carries a lone continuation byte (0x80) after "A".
+    let bytes = [
+        b'a', 0xFF, 0xFD, // row 0: "a"
+        b'A', 0x80, 0xFF, 0xFD, // row 1: invalid value starting at index 3
+    ];
+
+    let whole = decode_rsv(&bytes);
+    let DecodeRSVErrors::InvalidStringValue { offset, .. } = whole.unwrap_err() else {
+        panic!("expected InvalidStringValue");
+    };
+    assert_eq!(offset, 4);
+
+    let mut reader = RsvReader::new(Cursor::new(bytes.to_vec()));
+    assert_eq!(reader.next().unwrap().unwrap(), vec![Some("a".to_string())]);
+    match reader.next().unwrap() {
+        Err(DecodeRSVErrors::InvalidStringValue { offset, .. }) => assert_eq!(offset, 4),
+        other => panic!("expected absolute offset 4, got {other:?}"),
+    }
+}
@@ -0,0 +1,37 @@
+use rsv_data::core::{decode_rsv_values, encode_rsv_values, EncodeRSVErrors, RsvValue, NULL_VALUE};
+
+#[test]
+fn round_trips_text_null_and_bytes() {
+    let rows = vec![vec![
+        RsvValue::Text("hi".to_string()),
+        RsvValue::Null,
+        RsvValue::Bytes(vec![0x80, 0x81]),
+    ]];
+
+    let encoded = encode_rsv_values(&rows).expect("encoding valid values succeeds");
+    assert_eq!(decode_rsv_values(&encoded).unwrap(), rows);
+}
+
+#[test]
+fn decoder_emits_bytes_for_non_utf8_values() {
+    // "ok" then the invalid pair 0x80 0x81, each value terminated, one row.
+    let bytes = [b'o', b'k', 0xFF, 0x80, 0x81, 0xFF, 0xFD];
+    assert_eq!(
+        decode_rsv_values(&bytes).unwrap(),
+        vec![vec![
+            RsvValue::Text("ok".to_string()),
+            RsvValue::Bytes(vec![0x80, 0x81]),
+        ]]
+    );
+}
+
+#[test]
+fn encoder_rejects_reserved_bytes() {
+    let rows = vec![vec![RsvValue::Bytes(vec![0x01, NULL_VALUE])]];
+    match encode_rsv_values(&rows) {
+        Err(EncodeRSVErrors::ReservedByteInValue { index, byte }) => {
+            assert_eq!((index, byte), (1, NULL_VALUE));
+        }
+        other => panic!("expected ReservedByteInValue, got {other:?}"),
+    }
+}